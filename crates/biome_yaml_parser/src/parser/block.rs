@@ -13,24 +13,39 @@ use crate::lexer::YamlLexContext;
 
 use super::{
     YamlParser,
-    flow::{is_at_any_flow_node, is_at_flow_yaml_node, parse_any_flow_node, parse_flow_yaml_node},
+    flow::{
+        is_at_any_flow_node, is_at_flow_yaml_node, parse_any_flow_node_after_properties,
+        parse_flow_yaml_node,
+    },
     implicit::try_parse_implicit_flow_yaml_node,
-    parse_error::expected_block_mapping,
+    parse_error::{expected_block_mapping, expected_block_sequence},
+    properties::{is_at_alias_node, parse_alias_node, parse_node_properties},
 };
 
 pub(crate) fn parse_any_block_node(p: &mut YamlParser, context: YamlLexContext) -> ParsedSyntax {
+    // `*ref: value` is a legal implicit block-mapping entry keyed by an
+    // alias, so block-node detection must run before the bare-alias
+    // short-circuit below, otherwise the alias would swallow the whole
+    // node and leave `: value` dangling.
+    if is_at_block_node(p) {
+        return Present(parse_block_in_block_node(p));
+    }
+    if is_at_alias_node(p) {
+        return Present(parse_alias_node(p));
+    }
+    let properties = parse_node_properties(p);
     if is_at_block_node(p) {
         Present(parse_block_in_block_node(p))
-    } else if is_at_any_flow_node(p) {
-        Present(parse_flow_in_block_node(p))
+    } else if is_at_any_flow_node(p) || properties.is_present() {
+        Present(parse_flow_in_block_node(p, properties))
     } else {
         Absent
     }
 }
 
-fn parse_flow_in_block_node(p: &mut YamlParser) -> CompletedMarker {
+fn parse_flow_in_block_node(p: &mut YamlParser, properties: ParsedSyntax) -> CompletedMarker {
     let m = p.start();
-    parse_any_flow_node(p, YamlLexContext::FlowOut);
+    parse_any_flow_node_after_properties(p, YamlLexContext::FlowOut, properties);
     p.expect(NEWLINE);
     m.complete(p, YAML_FLOW_IN_BLOCK_NODE)
 }
@@ -45,46 +60,112 @@ fn parse_block_in_block_node(p: &mut YamlParser) -> CompletedMarker {
 
 fn parse_block_collection(p: &mut YamlParser) -> CompletedMarker {
     let m = p.start();
+    let is_sequence = is_at_block_sequence(p);
     if p.indent_level != 0 {
-        p.expect(INDENT);
+        if is_sequence {
+            // Indentless sequences: entries are allowed to sit at the same
+            // indentation as the enclosing mapping key, in which case the
+            // lexer does not emit an INDENT token before them.
+            p.eat(INDENT);
+        } else {
+            p.expect(INDENT);
+        }
     }
     p.indent_level += 1;
-    parse_block_mapping(p);
+    if is_sequence {
+        parse_block_sequence(p);
+    } else {
+        parse_block_mapping(p);
+    }
     p.indent_level -= 1;
     p.eat(DEDENT);
     m.complete(p, YAML_BLOCK_COLLECTION)
 }
 
+// The lexer produces a block scalar (header and body together) as a single
+// `LITERAL_BLOCK_LITERAL` or `FOLDED_BLOCK_LITERAL` token, and this parser
+// bumps that token directly into one `YAML_BLOCK_SCALAR` node. The header is
+// not split into its own node, and its chomping (`-`/`+`), explicit
+// indentation and folding indicators are not interpreted here.
 fn parse_block_scalar(p: &mut YamlParser) -> CompletedMarker {
     let m = p.start();
     match p.cur() {
         LITERAL_BLOCK_LITERAL => {
-            parse_literal_scalar(p);
+            p.bump(LITERAL_BLOCK_LITERAL);
         }
         FOLDED_BLOCK_LITERAL => {
-            parse_folded_scalar(p);
+            p.bump(FOLDED_BLOCK_LITERAL);
         }
         _ => {}
     }
     m.complete(p, YAML_BLOCK_SCALAR)
 }
 
-fn parse_literal_scalar(p: &mut YamlParser) -> CompletedMarker {
+fn parse_block_mapping(p: &mut YamlParser) -> CompletedMarker {
     let m = p.start();
-    p.bump(LITERAL_BLOCK_LITERAL);
-    m.complete(p, YAML_LITERAL_SCALAR)
+    BlockMapEntryList.parse_list(p);
+    m.complete(p, YAML_BLOCK_MAPPING)
 }
 
-fn parse_folded_scalar(p: &mut YamlParser) -> CompletedMarker {
+fn parse_block_sequence(p: &mut YamlParser) -> CompletedMarker {
     let m = p.start();
-    p.bump(FOLDED_BLOCK_LITERAL);
-    m.complete(p, YAML_FOLDED_SCALAR)
+    BlockSeqEntryList.parse_list(p);
+    m.complete(p, YAML_BLOCK_SEQUENCE)
 }
 
-fn parse_block_mapping(p: &mut YamlParser) -> CompletedMarker {
+#[derive(Default)]
+pub(crate) struct BlockSeqEntryList;
+
+impl ParseNodeList for BlockSeqEntryList {
+    type Kind = YamlSyntaxKind;
+    type Parser<'source> = YamlParser<'source>;
+
+    const LIST_KIND: Self::Kind = YAML_BLOCK_SEQUENCE_ENTRY_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_block_sequence_entry(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(DEDENT)
+        // If this is the top level block sequence, it ends at the next
+        // document boundary (`---`/`...`) rather than a DEDENT.
+        || p.at(DOC_END)
+        || p.at(T![---])
+        // Indentless sequences don't dedent when the sequence ends, so we
+        // also stop as soon as the next entry indicator is no longer there.
+        || !is_at_block_sequence(p)
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> biome_parser::parse_recovery::RecoveryResult {
+        parsed_element.or_recover(p, &BlockSeqEntryListParseRecovery, expected_block_sequence)
+    }
+}
+
+struct BlockSeqEntryListParseRecovery;
+
+impl ParseRecovery for BlockSeqEntryListParseRecovery {
+    type Kind = YamlSyntaxKind;
+    type Parser<'source> = YamlParser<'source>;
+    const RECOVERED_KIND: Self::Kind = YAML_BOGUS_BLOCK_SEQUENCE_ENTRY;
+
+    fn is_at_recovered(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(DEDENT) || p.at(DOC_END) || p.at(T![---])
+    }
+}
+
+fn parse_block_sequence_entry(p: &mut YamlParser) -> ParsedSyntax {
+    if !is_at_block_sequence(p) {
+        return Absent;
+    }
     let m = p.start();
-    BlockMapEntryList.parse_list(p);
-    m.complete(p, YAML_BLOCK_MAPPING)
+    p.bump(T![-]);
+    parse_any_block_node(p, YamlLexContext::BlockIn).ok();
+    Present(m.complete(p, YAML_BLOCK_SEQUENCE_ENTRY))
 }
 
 #[derive(Default)]
@@ -102,8 +183,10 @@ impl ParseNodeList for BlockMapEntryList {
 
     fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
         p.at(DEDENT)
-        // If this is the top level block mapping
+        // If this is the top level block mapping, it ends at the next
+        // document boundary (`---`/`...`) rather than a DEDENT.
         || p.at(DOC_END)
+        || p.at(T![---])
     }
 
     fn recover(
@@ -123,7 +206,7 @@ impl ParseRecovery for BlockMapEntryListParseRecovery {
     const RECOVERED_KIND: Self::Kind = YAML_BOGUS_BLOCK_MAP_ENTRY;
 
     fn is_at_recovered(&self, p: &mut Self::Parser<'_>) -> bool {
-        p.at(DEDENT)
+        p.at(DEDENT) || p.at(DOC_END) || p.at(T![---])
     }
 }
 
@@ -160,7 +243,7 @@ fn parse_block_map_explicit_value(p: &mut YamlParser) -> CompletedMarker {
 }
 
 fn parse_block_map_implicit_entry(p: &mut YamlParser) -> ParsedSyntax {
-    if !is_at_flow_yaml_node(p) {
+    if !is_at_flow_yaml_node(p) && !is_at_alias_node(p) {
         return Absent;
     }
     let m = p.start();
@@ -170,7 +253,11 @@ fn parse_block_map_implicit_entry(p: &mut YamlParser) -> ParsedSyntax {
 }
 
 fn parse_block_map_implicit_key(p: &mut YamlParser) -> CompletedMarker {
-    parse_flow_yaml_node(p, YamlLexContext::BlockKey)
+    if is_at_alias_node(p) {
+        parse_alias_node(p)
+    } else {
+        parse_flow_yaml_node(p, YamlLexContext::BlockKey)
+    }
 }
 
 fn parse_block_map_implicit_value(p: &mut YamlParser) -> CompletedMarker {
@@ -185,13 +272,17 @@ fn parse_block_map_implicit_value(p: &mut YamlParser) -> CompletedMarker {
 }
 
 fn is_at_block_node(p: &mut YamlParser) -> bool {
-    is_at_block_mapping(p)
+    is_at_block_mapping(p) || is_at_block_sequence(p)
 }
 
 fn is_at_block_mapping(p: &mut YamlParser) -> bool {
     is_at_explicit_mapping_key(p) || is_at_implicit_mapping_key(p)
 }
 
+fn is_at_block_sequence(p: &YamlParser) -> bool {
+    p.at(T![-])
+}
+
 fn is_at_explicit_mapping_key(p: &YamlParser) -> bool {
     p.at(QUESTION)
 }
@@ -204,6 +295,16 @@ fn is_at_explicit_mapping_key(p: &YamlParser) -> bool {
 // not an implicit key but just a normal flow node. In that case the parser still has to rewind and
 // parse the flow node under FLOW_OUT context.
 fn is_at_implicit_mapping_key(p: &mut YamlParser) -> bool {
+    if is_at_alias_node(p) {
+        // An alias is a complete node by itself, so there is no implicit-key
+        // length/single-line constraint to violate: just look past it for
+        // the `:` indicator, same as for scalar keys.
+        let checkpoint = p.checkpoint();
+        parse_alias_node(p);
+        let is_at_indicator = p.at(T![:]);
+        p.rewind(checkpoint);
+        return is_at_indicator;
+    }
     let checkpoint = p.checkpoint();
     let implicit_key = try_parse_implicit_flow_yaml_node(p);
     let violated_implicit_key_constraint = implicit_key.is_err();
@@ -0,0 +1,142 @@
+use biome_parser::{
+    CompletedMarker, Parser,
+    parse_lists::ParseNodeList,
+    parse_recovery::{ParseRecovery, RecoveryResult},
+    prelude::ParsedSyntax::{self, *},
+};
+use biome_yaml_syntax::{
+    T,
+    YamlSyntaxKind::{self, *},
+};
+
+use crate::lexer::YamlLexContext;
+
+use super::{
+    YamlParser,
+    block::parse_any_block_node,
+    parse_error::{expected_directive, expected_document},
+};
+
+/// Parses the whole input as a `document_list`:
+/// `STREAM-START implicit_document? explicit_document* STREAM-END`.
+pub(crate) fn parse_document_list(p: &mut YamlParser) -> CompletedMarker {
+    DocumentList.parse_list(p)
+}
+
+#[derive(Default)]
+struct DocumentList;
+
+impl ParseNodeList for DocumentList {
+    type Kind = YamlSyntaxKind;
+    type Parser<'source> = YamlParser<'source>;
+
+    const LIST_KIND: Self::Kind = YAML_DOCUMENT_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_document(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(EOF)
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover(p, &DocumentListParseRecovery, expected_document)
+    }
+}
+
+struct DocumentListParseRecovery;
+
+impl ParseRecovery for DocumentListParseRecovery {
+    type Kind = YamlSyntaxKind;
+    type Parser<'source> = YamlParser<'source>;
+    const RECOVERED_KIND: Self::Kind = YAML_BOGUS_DOCUMENT;
+
+    fn is_at_recovered(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![---]) || p.at(DOC_END) || p.at(EOF)
+    }
+}
+
+fn parse_document(p: &mut YamlParser) -> ParsedSyntax {
+    if p.at(EOF) {
+        return Absent;
+    }
+    let start = p.source().position();
+    let m = p.start();
+    // Every document starts fresh, regardless of how the previous one ended.
+    p.indent_level = 0;
+    DirectiveList.parse_list(p);
+    p.eat(T![---]);
+    parse_any_block_node(p, YamlLexContext::BlockOut).ok();
+    while p.at(DOC_END) {
+        p.bump(DOC_END);
+    }
+    if p.source().position() == start {
+        // Nothing was actually consumed: no directives, no `---`, no block
+        // node, no `...`. Returning `Present` here regardless would let
+        // `DocumentList` call back into this function forever without the
+        // cursor ever advancing, since `is_at_list_end` only checks `EOF`.
+        // Abandon the empty marker and report `Absent` instead, so
+        // `DocumentListParseRecovery` bumps the offending token into a
+        // `YAML_BOGUS_DOCUMENT` and the list makes progress.
+        m.abandon(p);
+        return Absent;
+    }
+    Present(m.complete(p, YAML_DOCUMENT))
+}
+
+#[derive(Default)]
+struct DirectiveList;
+
+impl ParseNodeList for DirectiveList {
+    type Kind = YamlSyntaxKind;
+    type Parser<'source> = YamlParser<'source>;
+
+    const LIST_KIND: Self::Kind = YAML_DIRECTIVE_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_directive(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        !is_at_directive(p)
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover(p, &DirectiveListParseRecovery, expected_directive)
+    }
+}
+
+struct DirectiveListParseRecovery;
+
+impl ParseRecovery for DirectiveListParseRecovery {
+    type Kind = YamlSyntaxKind;
+    type Parser<'source> = YamlParser<'source>;
+    const RECOVERED_KIND: Self::Kind = YAML_BOGUS_DIRECTIVE;
+
+    fn is_at_recovered(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(NEWLINE) || p.at(T![---]) || p.at(DOC_END) || p.at(EOF)
+    }
+}
+
+fn parse_directive(p: &mut YamlParser) -> ParsedSyntax {
+    if !is_at_directive(p) {
+        return Absent;
+    }
+    let m = p.start();
+    p.bump(DIRECTIVE_LITERAL);
+    p.expect(NEWLINE);
+    Present(m.complete(p, YAML_DIRECTIVE))
+}
+
+fn is_at_directive(p: &YamlParser) -> bool {
+    p.at(DIRECTIVE_LITERAL)
+}
@@ -1,24 +1,79 @@
 use biome_parser::{
     CompletedMarker, Parser,
+    parse_lists::ParseSeparatedList,
+    parse_recovery::{ParseRecovery, RecoveryResult},
     prelude::{
         ParsedSyntax::{self, *},
         TokenSource,
     },
 };
-use biome_yaml_syntax::YamlSyntaxKind::{self, *};
+use biome_yaml_syntax::{
+    T,
+    YamlSyntaxKind::{self, *},
+};
 
 use crate::lexer::YamlLexContext;
 
-use super::YamlParser;
+use super::{
+    YamlParser,
+    parse_error::{expected_flow_mapping_entry, expected_flow_sequence_element},
+    properties::{is_at_alias_node, is_at_node_properties, parse_alias_node, parse_node_properties},
+};
 
 pub(crate) fn parse_any_flow_node(p: &mut YamlParser, context: YamlLexContext) -> CompletedMarker {
-    parse_flow_yaml_node(p, context)
+    if is_at_alias_node(p) {
+        return parse_alias_node(p);
+    }
+    let properties = parse_node_properties(p);
+    parse_any_flow_node_after_properties(p, context, properties)
+}
+
+// Shared tail of `parse_any_flow_node`, factored out so block-node callers
+// that must parse properties themselves (to decide between block and flow
+// content, e.g. `key: &anchor\n  a: 1`) can hand the already-parsed
+// properties back in here instead of parsing them a second time. Parsing
+// them twice left block-context callers with an empty `properties` here and
+// made the properties-only shortcut below unreachable from that path.
+pub(crate) fn parse_any_flow_node_after_properties(
+    p: &mut YamlParser,
+    context: YamlLexContext,
+    properties: ParsedSyntax,
+) -> CompletedMarker {
+    if p.at(T!['[']) {
+        parse_flow_sequence(p)
+    } else if p.at(T!['{']) {
+        parse_flow_mapping(p)
+    } else if is_at_flow_yaml_node(p) {
+        parse_flow_yaml_node(p, context)
+    } else if let Present(properties) = properties {
+        // Properties with no following content (e.g. a bare `&anchor`): the
+        // properties node itself is the whole node, per `properties
+        // block_content?`. Don't fall through to `parse_flow_yaml_node`,
+        // which would otherwise bump whatever token happens to follow.
+        properties
+    } else {
+        // Reached when a flow node was expected (guarded by
+        // `is_at_any_flow_node`/`properties.is_present()` upstream) but nothing was
+        // actually there, e.g. after rewinding a failed lookahead. Produce
+        // an empty node rather than bumping an arbitrary token.
+        let m = p.start();
+        m.complete(p, YAML_FLOW_YAML_NODE)
+    }
 }
 
-// TODO: parse node properties
 pub(crate) fn parse_flow_yaml_node(p: &mut YamlParser, context: YamlLexContext) -> CompletedMarker {
     let m = p.start();
-    parse_plain_scalar(p, context);
+    match p.cur() {
+        SINGLE_QUOTED_LITERAL => {
+            parse_single_quoted_scalar(p, context);
+        }
+        DOUBLE_QUOTED_LITERAL => {
+            parse_double_quoted_scalar(p, context);
+        }
+        _ => {
+            parse_plain_scalar(p, context);
+        }
+    }
     m.complete(p, YAML_FLOW_YAML_NODE)
 }
 
@@ -29,14 +84,180 @@ fn parse_plain_scalar(p: &mut YamlParser, context: YamlLexContext) -> CompletedM
     m.complete(p, YAML_PLAIN_SCALAR)
 }
 
+fn parse_single_quoted_scalar(p: &mut YamlParser, context: YamlLexContext) -> CompletedMarker {
+    p.re_lex(context);
+    let m = p.start();
+    p.bump(SINGLE_QUOTED_LITERAL);
+    m.complete(p, YAML_SINGLE_QUOTED_SCALAR)
+}
+
+fn parse_double_quoted_scalar(p: &mut YamlParser, context: YamlLexContext) -> CompletedMarker {
+    p.re_lex(context);
+    let m = p.start();
+    p.bump(DOUBLE_QUOTED_LITERAL);
+    m.complete(p, YAML_DOUBLE_QUOTED_SCALAR)
+}
+
+fn parse_flow_sequence(p: &mut YamlParser) -> CompletedMarker {
+    let m = p.start();
+    p.bump(T!['[']);
+    FlowSequenceEntryList.parse_list(p);
+    p.expect(T![']']);
+    m.complete(p, YAML_FLOW_SEQUENCE)
+}
+
+#[derive(Default)]
+struct FlowSequenceEntryList;
+
+impl ParseSeparatedList for FlowSequenceEntryList {
+    type Kind = YamlSyntaxKind;
+    type Parser<'source> = YamlParser<'source>;
+
+    const LIST_KIND: Self::Kind = YAML_FLOW_SEQUENCE_ENTRY_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        if !is_at_any_flow_node(p) {
+            return Absent;
+        }
+        Present(parse_any_flow_node(p, YamlLexContext::FlowIn))
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![']'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover(
+            p,
+            &FlowSequenceEntryListParseRecovery,
+            expected_flow_sequence_element,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+
+    fn allow_trailing_separating_element(&self) -> bool {
+        true
+    }
+}
+
+struct FlowSequenceEntryListParseRecovery;
+
+impl ParseRecovery for FlowSequenceEntryListParseRecovery {
+    type Kind = YamlSyntaxKind;
+    type Parser<'source> = YamlParser<'source>;
+    const RECOVERED_KIND: Self::Kind = YAML_BOGUS_FLOW_SEQUENCE_ENTRY;
+
+    fn is_at_recovered(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![,]) || p.at(T![']'])
+    }
+}
+
+fn parse_flow_mapping(p: &mut YamlParser) -> CompletedMarker {
+    let m = p.start();
+    p.bump(T!['{']);
+    FlowMapEntryList.parse_list(p);
+    p.expect(T!['}']);
+    m.complete(p, YAML_FLOW_MAPPING)
+}
+
+#[derive(Default)]
+struct FlowMapEntryList;
+
+impl ParseSeparatedList for FlowMapEntryList {
+    type Kind = YamlSyntaxKind;
+    type Parser<'source> = YamlParser<'source>;
+
+    const LIST_KIND: Self::Kind = YAML_FLOW_MAP_ENTRY_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_flow_map_entry(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T!['}'])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover(p, &FlowMapEntryListParseRecovery, expected_flow_mapping_entry)
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+
+    fn allow_trailing_separating_element(&self) -> bool {
+        true
+    }
+}
+
+struct FlowMapEntryListParseRecovery;
+
+impl ParseRecovery for FlowMapEntryListParseRecovery {
+    type Kind = YamlSyntaxKind;
+    type Parser<'source> = YamlParser<'source>;
+    const RECOVERED_KIND: Self::Kind = YAML_BOGUS_FLOW_MAP_ENTRY;
+
+    fn is_at_recovered(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![,]) || p.at(T!['}'])
+    }
+}
+
+// A flow pair allows any combination of an optional key, optional `:` and
+// optional value, e.g. `a: 1`, `a`, `: 1`, or even a bare `?`. An empty
+// entry between two commas is not a pair and is reported as an error.
+fn parse_flow_map_entry(p: &mut YamlParser) -> ParsedSyntax {
+    if !is_at_flow_map_entry(p) {
+        return Absent;
+    }
+    let m = p.start();
+    p.eat(T![?]);
+    if is_at_any_flow_node(p) {
+        parse_any_flow_node(p, YamlLexContext::FlowIn);
+    }
+    if p.at(T![:]) {
+        p.bump(T![:]);
+        if is_at_any_flow_node(p) {
+            parse_any_flow_node(p, YamlLexContext::FlowIn);
+        }
+    }
+    Present(m.complete(p, YAML_FLOW_MAP_ENTRY))
+}
+
+fn is_at_flow_map_entry(p: &YamlParser) -> bool {
+    p.at(T![?]) || p.at(T![:]) || is_at_any_flow_node(p)
+}
+
 pub(crate) fn is_at_any_flow_node(p: &YamlParser) -> bool {
-    is_at_flow_yaml_node(p)
+    is_at_alias_node(p)
+        || is_at_node_properties(p)
+        || p.at(T!['['])
+        || p.at(T!['{'])
+        || is_at_flow_yaml_node(p)
 }
 
 pub(crate) fn is_at_flow_yaml_node(p: &YamlParser) -> bool {
-    is_at_plain_scalar(p)
+    is_at_plain_scalar(p) || is_at_single_quoted_scalar(p) || is_at_double_quoted_scalar(p)
 }
 
 fn is_at_plain_scalar(p: &YamlParser) -> bool {
     p.at(PLAIN_LITERAL)
 }
+
+fn is_at_single_quoted_scalar(p: &YamlParser) -> bool {
+    p.at(SINGLE_QUOTED_LITERAL)
+}
+
+fn is_at_double_quoted_scalar(p: &YamlParser) -> bool {
+    p.at(DOUBLE_QUOTED_LITERAL)
+}
@@ -15,7 +15,7 @@ use biome_yaml_syntax::{
 
 use crate::lexer::YamlLexContext;
 
-use super::YamlParser;
+use super::{YamlParser, flow::is_at_flow_yaml_node};
 
 const MAX_IMPLICIT_KEY_SIZE: u32 = 1024;
 
@@ -24,24 +24,31 @@ pub(crate) struct ImplicitConstraintViolation;
 pub(crate) fn try_parse_implicit_flow_yaml_node(
     p: &mut YamlParser,
 ) -> Result<ParsedSyntax, ImplicitConstraintViolation> {
-    if !p.at(PLAIN_LITERAL) {
+    if !is_at_flow_yaml_node(p) {
         return Ok(Absent);
     }
     let m = p.start();
     let start_pos = p.source().position();
-    try_parse_plain_scalar(p, YamlLexContext::BlockKey, start_pos)?;
+    try_parse_scalar(p, YamlLexContext::BlockKey, start_pos)?;
     Ok(Present(m.complete(p, YAML_FLOW_YAML_NODE)))
 }
 
-fn try_parse_plain_scalar(
+fn try_parse_scalar(
     p: &mut YamlParser,
     context: YamlLexContext,
     start_pos: TextSize,
 ) -> Result<CompletedMarker, ImplicitConstraintViolation> {
     p.re_lex(context);
+    // Quoted scalars are also legal implicit keys, but they are still bound
+    // by the same 1024-char / single-line constraint as plain scalars.
+    let (token_kind, node_kind) = match p.cur() {
+        SINGLE_QUOTED_LITERAL => (SINGLE_QUOTED_LITERAL, YAML_SINGLE_QUOTED_SCALAR),
+        DOUBLE_QUOTED_LITERAL => (DOUBLE_QUOTED_LITERAL, YAML_DOUBLE_QUOTED_SCALAR),
+        _ => (PLAIN_LITERAL, YAML_PLAIN_SCALAR),
+    };
     let m = p.start();
-    expect_in_implicit_constrain(p, PLAIN_LITERAL, start_pos);
-    Ok(m.complete(p, YAML_PLAIN_SCALAR))
+    expect_in_implicit_constrain(p, token_kind, start_pos)?;
+    Ok(m.complete(p, node_kind))
 }
 
 fn expect_in_implicit_constrain(
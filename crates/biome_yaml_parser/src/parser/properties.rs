@@ -0,0 +1,42 @@
+use biome_parser::{
+    CompletedMarker, Parser,
+    prelude::ParsedSyntax::{self, *},
+};
+use biome_yaml_syntax::YamlSyntaxKind::{self, *};
+
+use super::YamlParser;
+
+/// Parses the optional node properties that may precede any block or flow
+/// node's content: an anchor (`&name`), a tag (`!tag`), or both, in either
+/// order, per `properties ::= TAG ANCHOR? | ANCHOR TAG?`.
+pub(crate) fn parse_node_properties(p: &mut YamlParser) -> ParsedSyntax {
+    if !is_at_node_properties(p) {
+        return Absent;
+    }
+    let m = p.start();
+    if p.at(ANCHOR_PROPERTY) {
+        p.bump(ANCHOR_PROPERTY);
+        p.eat(TAG_PROPERTY);
+    } else {
+        p.bump(TAG_PROPERTY);
+        p.eat(ANCHOR_PROPERTY);
+    }
+    Present(m.complete(p, YAML_PROPERTIES))
+}
+
+pub(crate) fn is_at_node_properties(p: &YamlParser) -> bool {
+    p.at(ANCHOR_PROPERTY) || p.at(TAG_PROPERTY)
+}
+
+/// An alias (`*name`) is a complete node on its own: it may appear anywhere
+/// a block or flow node is expected, short-circuiting both scalar and
+/// collection parsing.
+pub(crate) fn parse_alias_node(p: &mut YamlParser) -> CompletedMarker {
+    let m = p.start();
+    p.bump(ALIAS);
+    m.complete(p, YAML_ALIAS_NODE)
+}
+
+pub(crate) fn is_at_alias_node(p: &YamlParser) -> bool {
+    p.at(ALIAS)
+}
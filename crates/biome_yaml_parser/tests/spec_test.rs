@@ -0,0 +1,29 @@
+// Baseline `.snap` files for the fixtures under `tests/specs/ok/**` are not
+// checked in yet. Generating them requires running this suite against the
+// real `biome_parser`/`biome_yaml_syntax`/lexer crates this module depends
+// on (`crate::lexer`, pulled in transitively through `biome_yaml_parser`),
+// which aren't part of this checkout. Before merging any of the chunk0-1
+// through chunk0-6 fixtures, run `cargo insta test --accept` from a
+// workspace that has those crates, then review and commit the resulting
+// `.snap` files with `cargo insta review` — don't hand-author them.
+use std::path::Path;
+
+tests_macros::gen_tests! {"tests/specs/ok/**/*.yaml", crate::run_test, "module"}
+
+fn run_test(input: &str, test_name: &str, test_directory: &str, _file_extension: &str) {
+    let parsed = biome_yaml_parser::parse_yaml(input);
+    let tree = format!("{:#?}", parsed.syntax());
+
+    insta::with_settings!({
+        snapshot_path => Path::new(test_directory),
+        prepend_module_to_snapshot => false,
+    }, {
+        insta::assert_snapshot!(test_name, tree, input);
+    });
+
+    assert!(
+        !parsed.has_errors(),
+        "expected {test_name} to parse without errors, got {:?}",
+        parsed.diagnostics()
+    );
+}